@@ -1,138 +1,369 @@
+#![cfg_attr(feature = "nightly", feature(try_trait_v2, try_trait_v2_residual))]
+
 //! # The outcome crate
-//! Type `Outcome` represents a success or failure: Every `Outcome` is either `Success` or `Failure`
-//! 
+//! Type `Outcome<S, F>` represents a success or failure, each of which may carry a value: every
+//! `Outcome` is either `Success(S)`, `Failure(F)`, or `Forward`.
+//!
+//! The payload-free form used throughout earlier versions of this crate is still available as
+//! `Outcome`, which is just `Outcome<(), ()>` thanks to the enum's default type parameters.
+//!
+//! With the `nightly` feature enabled, `Outcome` implements `Try`/`FromResidual`, so `?` can be
+//! used to short-circuit on `Failure` inside a function returning `Outcome`, the same way it does
+//! for `Result`. A `Result` or `Option` used with `?` inside such a function converts cleanly:
+//! `Err`/`None` become `Failure`, `Ok`/`Some` continue.
+//!
 //! ```
 //! use outcome::*;
-//! 
-//! fn do_something() -> Outcome {
-//!     Success
+//!
+//! fn do_something() -> Outcome<i32, String> {
+//!     Success(42)
 //! }
-//! 
+//!
 //! // The return value is an outcome
 //! let result = do_something();
-//! 
+//!
 //! // Pattern Match
 //! match result {
-//!     Success => println!("Well done!"),
-//!     Failure => println!("Oh well :("),
+//!     Success(n) => println!("Well done! Got {}", n),
+//!     Failure(e) => println!("Oh well :( {}", e),
+//!     Forward => println!("Not my problem!"),
 //! }
 //! ```
-//! 
+//!
 //! # Examples
 //! Using `and_then` on an `Outcome`:
-//! 
+//!
 //! ```
 //! use outcome::*;
-//! 
-//! // Returns `Failure`
-//! let result = Outcome::from_bool(false);
-//! 
-//! match result.and_then(|| Success) {
-//!     Success => println!("Success! :)"),
-//!     Failure => println!("Failure :("),
+//!
+//! // Returns `Failure("nope")`
+//! let result: Outcome<i32, &str> = Failure("nope");
+//!
+//! match result.and_then(|n| Success(n + 1)) {
+//!     Success(n) => println!("Success! Got {}", n),
+//!     Failure(e) => println!("Failure :( {}", e),
+//!     Forward => println!("Forward..."),
 //! }
 //! ```
-//! 
-//! Using `or_none` on an `Outcome` to transform it into an `Option`:
-//! 
+//!
+//! Using `success` to transform an `Outcome` into an `Option`:
+//!
 //! ```
 //! use outcome::*;
-//! 
-//! let result = Success;
-//! 
-//! // Encapsulates arg within an option
-//! match result.or_none("hello!") {
-//!     Some(s) => println!("{}", s),
+//!
+//! let result: Outcome<i32, &str> = Success(42);
+//!
+//! match result.success() {
+//!     Some(n) => println!("{}", n),
 //!     None => println!("Nothing here!"),
 //! }
 //! ```
+//!
+//! `Outcome` implements `iter`/`into_iter`, yielding the `Success` value or nothing, which makes
+//! it composable with iterator chains:
+//!
+//! ```
+//! use outcome::*;
+//!
+//! let outcomes: Vec<Outcome<i32, &str>> = vec![Success(1), Failure("bad"), Success(3)];
+//! let successes: Vec<i32> = outcomes.iter().flatten().cloned().collect();
+//!
+//! assert_eq!(successes, vec![1, 3]);
+//! ```
 
-pub use Outcome::{Success, Failure};
+use std::fmt;
+use std::iter::{FromIterator, FusedIterator};
+#[cfg(feature = "nightly")]
+use std::convert::Infallible;
+#[cfg(feature = "nightly")]
+use std::ops::{ControlFlow, FromResidual, Residual, Try};
+
+pub use Outcome::{Success, Failure, Forward};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
-pub enum Outcome {
-    /// Successful
-    Success,
-    /// Not successful
-    Failure
+pub enum Outcome<S = (), F = ()> {
+    /// Contains the success value
+    Success(S),
+    /// Contains the failure value
+    Failure(F),
+    /// Neither successful nor a failure; control should pass to the next handler
+    Forward
 }
 
-impl Outcome {
-    /// Returns `Success` if `good` is `true`, otherwise return `Failure`
-    /// 
+impl<S, F> Outcome<S, F> {
+    /// Returns `true` if the outcome is a `Success`
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Outcome::from_bool(true);
-    /// 
-    /// assert_eq!(result, Success);
+    ///
+    /// let result: Outcome<i32, ()> = Success(2);
+    ///
+    /// assert!(result.is_success());
     /// ```
-    pub fn from_bool(good: bool) -> Outcome {
-        match good {
-            true => Success,
-            false => Failure,
+    pub fn is_success(&self) -> bool {
+        matches!(*self, Success(_))
+    }
+
+    /// Returns `true` if the outcome is a `Failure`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<(), i32> = Failure(2);
+    ///
+    /// assert!(result.is_failure());
+    /// ```
+    pub fn is_failure(&self) -> bool {
+        matches!(*self, Failure(_))
+    }
+
+    /// Returns `true` if the outcome is a `Forward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<(), ()> = Forward;
+    ///
+    /// assert!(result.is_forward());
+    /// ```
+    pub fn is_forward(&self) -> bool {
+        matches!(*self, Forward)
+    }
+
+    /// Converts `self` into an `Option<S>`, consuming `self` and discarding the failure, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.success(), Some(2));
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// assert_eq!(result.success(), None);
+    /// ```
+    pub fn success(self) -> Option<S> {
+        match self {
+            Success(s) => Some(s),
+            Failure(_) | Forward => None,
         }
     }
 
-    /// Returns `true` if the outcome is a success
-    /// 
+    /// Converts `self` into an `Option<F>`, consuming `self` and discarding the success, if any
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Success;
-    /// 
-    /// assert!(result.is_success());
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// assert_eq!(result.failure(), Some("bad"));
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.failure(), None);
     /// ```
-    pub fn is_success(&self) -> bool {
-        *self == Success
+    pub fn failure(self) -> Option<F> {
+        match self {
+            Failure(f) => Some(f),
+            Success(_) | Forward => None,
+        }
     }
 
-    /// Returns `true` if the outcome is a failure
-    /// 
+    /// Maps an `Outcome<S, F>` to an `Outcome<U, F>` by applying `op` to a contained `Success`
+    /// value, leaving `Failure` and `Forward` untouched
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Failure;
-    /// 
-    /// assert!(result.is_failure());
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.map(|n| n * 2), Success(4));
     /// ```
-    pub fn is_failure(&self) -> bool {
-        !self.is_success()
+    pub fn map<U, O: FnOnce(S) -> U>(self, op: O) -> Outcome<U, F> {
+        match self {
+            Success(s) => Success(op(s)),
+            Failure(f) => Failure(f),
+            Forward => Forward,
+        }
     }
 
-    /// Transforms the `Outcome` into an `Option<T>`, mapping `Success` to `Some(good)` and `Failure` to `None`
-    /// 
+    /// Maps an `Outcome<S, F>` to an `Outcome<S, U>` by applying `op` to a contained `Failure`
+    /// value, leaving `Success` and `Forward` untouched
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Success;
-    /// 
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// assert_eq!(result.map_failure(|e| e.len()), Failure(3));
+    /// ```
+    pub fn map_failure<U, O: FnOnce(F) -> U>(self, op: O) -> Outcome<S, U> {
+        match self {
+            Success(s) => Success(s),
+            Failure(f) => Failure(op(f)),
+            Forward => Forward,
+        }
+    }
+
+    /// Converts from `Outcome<S, F>` to `Outcome<&S, &F>`
+    pub fn as_ref(&self) -> Outcome<&S, &F> {
+        match *self {
+            Success(ref s) => Success(s),
+            Failure(ref f) => Failure(f),
+            Forward => Forward,
+        }
+    }
+
+    /// Converts from `&mut Outcome<S, F>` to `Outcome<&mut S, &mut F>`
+    pub fn as_mut(&mut self) -> Outcome<&mut S, &mut F> {
+        match *self {
+            Success(ref mut s) => Success(s),
+            Failure(ref mut f) => Failure(f),
+            Forward => Forward,
+        }
+    }
+
+    /// Returns the `Success` value, otherwise panics with a message including `msg`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.expect("should have a value"), 2);
+    /// ```
+    pub fn expect(self, msg: &str) -> S where F: fmt::Debug {
+        match self {
+            Success(s) => s,
+            Failure(f) => panic!("{}: {:?}", msg, f),
+            Forward => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the `Success` value, otherwise panics
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.unwrap(), 2);
+    /// ```
+    pub fn unwrap(self) -> S where F: fmt::Debug {
+        match self {
+            Success(s) => s,
+            Failure(f) => panic!("called `Outcome::unwrap()` on a `Failure` value: {:?}", f),
+            Forward => panic!("called `Outcome::unwrap()` on a `Forward` value"),
+        }
+    }
+
+    /// Returns the `Success` value, otherwise returns `default`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// assert_eq!(result.unwrap_or(0), 0);
+    /// ```
+    pub fn unwrap_or(self, default: S) -> S {
+        match self {
+            Success(s) => s,
+            Failure(_) | Forward => default,
+        }
+    }
+
+    /// Returns the `Success` value, otherwise calls `op` and returns its result. `op` receives
+    /// `Some(failure)` for a `Failure` and `None` for a `Forward`, since `Forward` carries no
+    /// failure value to hand over — unlike `Result::unwrap_or_else`, this never fabricates one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// assert_eq!(result.unwrap_or_else(|e| e.map_or(0, str::len) as i32), 3);
+    ///
+    /// let result: Outcome<i32, &str> = Forward;
+    /// assert_eq!(result.unwrap_or_else(|e| e.map_or(0, str::len) as i32), 0);
+    /// ```
+    pub fn unwrap_or_else<O: FnOnce(Option<F>) -> S>(self, op: O) -> S {
+        match self {
+            Success(s) => s,
+            Failure(f) => op(Some(f)),
+            Forward => op(None),
+        }
+    }
+
+    /// Converts `self` into a `Result<S, Option<F>>`, mapping `Failure(f)` to `Err(Some(f))` and
+    /// `Forward` to `Err(None)` so a `Forward` can never be mistaken for a real failure value.
+    ///
+    /// Note this returns `Result<S, Option<F>>`, not a bare `Result<S, F>`: `Forward` carries no
+    /// failure value to put in the `Err` side, and fabricating one (e.g. via `F::default()`) would
+    /// make a `Forward` indistinguishable from a real failure that happens to default-construct to
+    /// the same value. Match on the `Option` to recover `Result<S, F>` where that's acceptable, or
+    /// use `or_forward`/`forward_then` to handle `Forward` explicitly beforehand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.into_result(), Ok(2));
+    ///
+    /// let result: Outcome<i32, &str> = Forward;
+    /// assert_eq!(result.into_result(), Err(None));
+    /// ```
+    pub fn into_result(self) -> Result<S, Option<F>> {
+        match self {
+            Success(s) => Ok(s),
+            Failure(f) => Err(Some(f)),
+            Forward => Err(None),
+        }
+    }
+
+    /// Transforms the outcome into an `Option<T>`, mapping `Success` to `Some(ok)` and
+    /// `Failure` or `Forward` to `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
     /// assert_eq!(result.or_none(42), Some(42));
     /// ```
     pub fn or_none<T>(self, ok: T) -> Option<T> {
         match self {
-            Success => Some(ok),
-            Failure => None,
+            Success(_) => Some(ok),
+            Failure(_) | Forward => None,
         }
     }
 
-    /// Transforms the `Outcome` into a `Result<T, E>`, mapping `Success` to `Ok(good)` and `Failure` to `Err(err)`
-    /// 
+    /// Transforms the outcome into a `Result<T, E>`, mapping `Success` to `Ok(good)` and
+    /// `Failure` or `Forward` to `Err(err)`
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Failure;
-    /// 
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    ///
     /// match result.or_err("good", "bad") {
     ///     Ok(success) => println!("{}", success),
     ///     Err(err) => println!("{}", err),
@@ -140,98 +371,488 @@ impl Outcome {
     /// ```
     pub fn or_err<T, E>(self, good: T, err: E) -> Result<T, E> {
         match self {
-            Success => Ok(good),
-            Failure => Err(err),
+            Success(_) => Ok(good),
+            Failure(_) | Forward => Err(err),
         }
     }
 
     /// Returns `good` if the outcome is `Success`, otherwise panics
-    /// 
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Success;
-    /// 
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
     /// assert_eq!(result.or_panic(42), 42);
     /// ```
     pub fn or_panic<T>(self, good: T) -> T {
         match self {
-            Success => good,
-            Failure => panic!("Called `Outcome::or_panic(...)` on a `Failure` value"),
+            Success(_) => good,
+            Failure(_) => panic!("Called `Outcome::or_panic(...)` on a `Failure` value"),
+            Forward => panic!("Called `Outcome::or_panic(...)` on a `Forward` value"),
+        }
+    }
+
+    /// Returns `outb` if the outcome is `Success`, otherwise returns the `Failure` or `Forward`
+    /// as-is. `Forward` short-circuits `and` just like `Failure` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// let chained: Outcome<i32, &str> = result.and(Failure("bad"));
+    /// assert_eq!(chained, Failure("bad"));
+    /// ```
+    pub fn and<U>(self, outb: Outcome<U, F>) -> Outcome<U, F> {
+        match self {
+            Success(_) => outb,
+            Failure(f) => Failure(f),
+            Forward => Forward,
         }
     }
 
-    /// Returns `Failure` if the outcome is `Failure`, otherwise returns `outb`
-    /// 
+    /// Returns `outb` if the outcome is `Failure` or `Forward`, otherwise returns the `Success`
+    /// as-is. `Forward` is treated as a non-success, so it falls through to `outb` just like
+    /// `Failure` does.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Success;
-    /// 
-    /// assert_eq!(result.and(Failure), Failure);
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// let recovered: Outcome<i32, &str> = result.or(Success(2));
+    /// assert_eq!(recovered, Success(2));
     /// ```
-    pub fn and(self, outb: Outcome) -> Outcome {
+    pub fn or<U>(self, outb: Outcome<S, U>) -> Outcome<S, U> {
         match self {
-            Success => outb,
-            Failure => Failure,
+            Success(s) => Success(s),
+            Failure(_) | Forward => outb,
         }
     }
 
-    /// Returns `Success` if the outcome is `Success`, otherwise returns `outb`
-    /// 
+    /// Calls `op` with the `Success` value if the outcome is `Success`, otherwise returns the
+    /// `Failure` or `Forward` as-is
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Failure;
-    /// 
-    /// assert_eq!(result.or(Success), Success);
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.and_then(|n| Success(n + 1)), Success(3));
     /// ```
-    pub fn or(self, outb: Outcome) -> Outcome {
+    pub fn and_then<U, O: FnOnce(S) -> Outcome<U, F>>(self, op: O) -> Outcome<U, F> {
         match self {
-            Success => Success,
-            Failure => outb,
+            Success(s) => op(s),
+            Failure(f) => Failure(f),
+            Forward => Forward,
         }
     }
 
-    /// Returns `Failure` if the outcome is `Failure`, otherwise calls `f` and returns result
-    /// 
+    /// Calls `op` with the failure value if the outcome is `Failure`, otherwise returns the
+    /// `Success` or `Forward` as-is. Unlike `or`, this can't treat `Forward` as a non-success the
+    /// same way `Failure` is, since `op` expects a real failure value and `Forward` has none to
+    /// give it; use `or_forward`/`forward_then` to recover from a `Forward` specifically.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Success;
-    /// 
-    /// assert_eq!(result.and_then(|| Failure), Failure);
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// let recovered: Outcome<i32, &str> = result.or_then(|_| Success(2));
+    /// assert_eq!(recovered, Success(2));
     /// ```
-    pub fn and_then<F: FnOnce() -> Outcome>(self, f: F) -> Outcome {
+    pub fn or_then<U, O: FnOnce(F) -> Outcome<S, U>>(self, op: O) -> Outcome<S, U> {
         match self {
-            Success => f(),
-            Failure => Failure,
+            Success(s) => Success(s),
+            Failure(f) => op(f),
+            Forward => Forward,
         }
     }
 
-    /// Returns `Success` if the outcome is `Success`, otherwise calls `f` and returns result
-    /// 
+    /// Returns `outb` if the outcome is `Forward`, otherwise returns the `Success` or `Failure`
+    /// as-is. This is the `Forward`-specific counterpart to `or`, letting the next handler in a
+    /// chain take over without masking an actual `Failure`.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use outcome::*;
-    /// 
-    /// let result = Failure;
-    /// 
-    /// assert_eq!(result.or_then(|| Success), Success);
+    ///
+    /// let result: Outcome<i32, &str> = Forward;
+    /// assert_eq!(result.or_forward(Success(2)), Success(2));
     /// ```
-    pub fn or_then<F: FnOnce() -> Outcome>(self, f: F) -> Outcome {
+    pub fn or_forward(self, outb: Outcome<S, F>) -> Outcome<S, F> {
         match self {
-            Success => Success,
-            Failure => f(),
+            Success(s) => Success(s),
+            Failure(f) => Failure(f),
+            Forward => outb,
         }
     }
-}
\ No newline at end of file
+
+    /// Calls `op` if the outcome is `Forward`, otherwise returns the `Success` or `Failure`
+    /// as-is. This is the `Forward`-specific counterpart to `and_then`/`or_then`, letting a
+    /// handler react to "not my problem" without swallowing real failures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Forward;
+    /// assert_eq!(result.forward_then(|| Success(2)), Success(2));
+    /// ```
+    pub fn forward_then<O: FnOnce() -> Outcome<S, F>>(self, op: O) -> Outcome<S, F> {
+        match self {
+            Success(s) => Success(s),
+            Failure(f) => Failure(f),
+            Forward => op(),
+        }
+    }
+}
+
+impl Outcome<(), ()> {
+    /// Returns `Success(())` if `good` is `true`, otherwise returns `Failure(())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result = Outcome::from_bool(true);
+    ///
+    /// assert_eq!(result, Success(()));
+    /// ```
+    pub fn from_bool(good: bool) -> Outcome {
+        match good {
+            true => Success(()),
+            false => Failure(()),
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<S, F> Try for Outcome<S, F> {
+    type Output = S;
+    type Residual = Outcome<Infallible, F>;
+
+    fn from_output(output: S) -> Self {
+        Success(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, S> {
+        match self {
+            Success(s) => ControlFlow::Continue(s),
+            Failure(f) => ControlFlow::Break(Failure(f)),
+            Forward => ControlFlow::Break(Forward),
+        }
+    }
+}
+
+// `Residual` maps the residual type back to an `Outcome` of any `Output`, which residual-generic
+// APIs such as `Iterator::try_collect` rely on; std implements it for `Result`/`Option` the same way.
+#[cfg(feature = "nightly")]
+impl<S, F> Residual<S> for Outcome<Infallible, F> {
+    type TryType = Outcome<S, F>;
+}
+
+#[cfg(feature = "nightly")]
+impl<S, F> FromResidual for Outcome<S, F> {
+    fn from_residual(residual: Outcome<Infallible, F>) -> Self {
+        match residual {
+            Failure(f) => Failure(f),
+            Forward => Forward,
+            Success(infallible) => match infallible {},
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<S, F, E> FromResidual<Result<Infallible, E>> for Outcome<S, F>
+where
+    F: From<E>,
+{
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Err(e) => Failure(F::from(e)),
+            Ok(infallible) => match infallible {},
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<S, F> FromResidual<Option<Infallible>> for Outcome<S, F>
+where
+    F: Default,
+{
+    fn from_residual(_residual: Option<Infallible>) -> Self {
+        Failure(F::default())
+    }
+}
+
+/// Collects a sequence of payload-free `Outcome`s into a single one: `Success(())` if every item
+/// is `Success`, otherwise the first `Failure` or `Forward` encountered.
+///
+/// # Examples
+///
+/// ```
+/// use outcome::*;
+///
+/// let result: Outcome<(), &str> = vec![Success(()), Success(())].into_iter().collect();
+/// assert_eq!(result, Success(()));
+///
+/// let result: Outcome<(), &str> = vec![Success(()), Failure("bad"), Success(())].into_iter().collect();
+/// assert_eq!(result, Failure("bad"));
+/// ```
+impl<F> FromIterator<Outcome<(), F>> for Outcome<(), F> {
+    fn from_iter<I: IntoIterator<Item = Outcome<(), F>>>(iter: I) -> Self {
+        for outcome in iter {
+            match outcome {
+                Success(()) => continue,
+                Failure(f) => return Failure(f),
+                Forward => return Forward,
+            }
+        }
+        Success(())
+    }
+}
+
+/// Collects a sequence of `Outcome<S, F>`s into `Outcome<Vec<S>, F>`: the successes are
+/// accumulated into a `Vec`, bailing out with the first `Failure` or `Forward` encountered.
+///
+/// # Examples
+///
+/// ```
+/// use outcome::*;
+///
+/// let result: Outcome<Vec<i32>, &str> = vec![Success(1), Success(2)].into_iter().collect();
+/// assert_eq!(result, Success(vec![1, 2]));
+///
+/// let result: Outcome<Vec<i32>, &str> = vec![Success(1), Failure("bad")].into_iter().collect();
+/// assert_eq!(result, Failure("bad"));
+/// ```
+impl<S, F> FromIterator<Outcome<S, F>> for Outcome<Vec<S>, F> {
+    fn from_iter<I: IntoIterator<Item = Outcome<S, F>>>(iter: I) -> Self {
+        let mut acc = Vec::new();
+        for outcome in iter {
+            match outcome {
+                Success(s) => acc.push(s),
+                Failure(f) => return Failure(f),
+                Forward => return Forward,
+            }
+        }
+        Success(acc)
+    }
+}
+
+/// Returns `Success(())` if every item yielded by `iter` is `Success`, otherwise the first
+/// `Failure` or `Forward` encountered. A one-liner for validating a batch of operations.
+///
+/// # Examples
+///
+/// ```
+/// use outcome::*;
+///
+/// let result: Outcome<(), &str> = all_success(vec![Success(()), Success(())]);
+/// assert_eq!(result, Success(()));
+/// ```
+pub fn all_success<I, F>(iter: I) -> Outcome<(), F>
+where
+    I: IntoIterator<Item = Outcome<(), F>>,
+{
+    iter.into_iter().collect()
+}
+
+/// Returns `true` if at least one item yielded by `iter` is `Success`
+///
+/// # Examples
+///
+/// ```
+/// use outcome::*;
+///
+/// let results: Vec<Outcome<(), &str>> = vec![Failure("bad"), Success(())];
+/// assert!(any_success(results));
+/// ```
+pub fn any_success<I, S, F>(iter: I) -> bool
+where
+    I: IntoIterator<Item = Outcome<S, F>>,
+{
+    iter.into_iter().any(|outcome| outcome.is_success())
+}
+
+/// An iterator over a reference to the `Success` value contained in an `Outcome`. Yields the
+/// value once if `Success`, otherwise yields nothing.
+pub struct Iter<'a, S> {
+    inner: Option<&'a S>,
+}
+
+impl<'a, S> Iterator for Iter<'a, S> {
+    type Item = &'a S;
+
+    fn next(&mut self) -> Option<&'a S> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.is_some() as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for Iter<'a, S> {
+    fn next_back(&mut self) -> Option<&'a S> {
+        self.inner.take()
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Iter<'a, S> {
+    fn len(&self) -> usize {
+        self.inner.is_some() as usize
+    }
+}
+
+impl<'a, S> FusedIterator for Iter<'a, S> {}
+
+/// An iterator over a mutable reference to the `Success` value contained in an `Outcome`. Yields
+/// the value once if `Success`, otherwise yields nothing.
+pub struct IterMut<'a, S> {
+    inner: Option<&'a mut S>,
+}
+
+impl<'a, S> Iterator for IterMut<'a, S> {
+    type Item = &'a mut S;
+
+    fn next(&mut self) -> Option<&'a mut S> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.is_some() as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for IterMut<'a, S> {
+    fn next_back(&mut self) -> Option<&'a mut S> {
+        self.inner.take()
+    }
+}
+
+impl<'a, S> ExactSizeIterator for IterMut<'a, S> {
+    fn len(&self) -> usize {
+        self.inner.is_some() as usize
+    }
+}
+
+impl<'a, S> FusedIterator for IterMut<'a, S> {}
+
+/// An iterator over the owned `Success` value contained in an `Outcome`. Yields the value once
+/// if `Success`, otherwise yields nothing.
+pub struct IntoIter<S> {
+    inner: Option<S>,
+}
+
+impl<S> Iterator for IntoIter<S> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<S> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.is_some() as usize;
+        (len, Some(len))
+    }
+}
+
+impl<S> DoubleEndedIterator for IntoIter<S> {
+    fn next_back(&mut self) -> Option<S> {
+        self.inner.take()
+    }
+}
+
+impl<S> ExactSizeIterator for IntoIter<S> {
+    fn len(&self) -> usize {
+        self.inner.is_some() as usize
+    }
+}
+
+impl<S> FusedIterator for IntoIter<S> {}
+
+impl<S, F> Outcome<S, F> {
+    /// Returns an iterator over the possibly contained `Success` value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<i32, &str> = Success(2);
+    /// assert_eq!(result.iter().next(), Some(&2));
+    ///
+    /// let result: Outcome<i32, &str> = Failure("bad");
+    /// assert_eq!(result.iter().next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, S> {
+        Iter { inner: self.as_ref().success() }
+    }
+
+    /// Returns an iterator over a mutable reference to the possibly contained `Success` value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let mut result: Outcome<i32, &str> = Success(2);
+    /// if let Some(n) = result.iter_mut().next() {
+    ///     *n += 1;
+    /// }
+    /// assert_eq!(result, Success(3));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, S> {
+        IterMut { inner: self.as_mut().success() }
+    }
+}
+
+impl<S, F> IntoIterator for Outcome<S, F> {
+    type Item = S;
+    type IntoIter = IntoIter<S>;
+
+    /// Returns a consuming iterator over the possibly contained `Success` value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use outcome::*;
+    ///
+    /// let result: Outcome<Vec<i32>, &str> = Success(vec![1, 2]);
+    /// assert_eq!(result.into_iter().next(), Some(vec![1, 2]));
+    /// ```
+    fn into_iter(self) -> IntoIter<S> {
+        IntoIter { inner: self.success() }
+    }
+}
+
+impl<'a, S, F> IntoIterator for &'a Outcome<S, F> {
+    type Item = &'a S;
+    type IntoIter = Iter<'a, S>;
+
+    fn into_iter(self) -> Iter<'a, S> {
+        self.iter()
+    }
+}
+
+impl<'a, S, F> IntoIterator for &'a mut Outcome<S, F> {
+    type Item = &'a mut S;
+    type IntoIter = IterMut<'a, S>;
+
+    fn into_iter(self) -> IterMut<'a, S> {
+        self.iter_mut()
+    }
+}
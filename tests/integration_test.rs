@@ -3,33 +3,277 @@ use outcome::*;
 
 #[test]
 fn is_success_failure() {
-    assert!(Success.is_success());
-    assert!(Failure.is_failure());
+    let success: Outcome<i32, &str> = Success(1);
+    let failure: Outcome<i32, &str> = Failure("bad");
+    let forward: Outcome<i32, &str> = Forward;
+
+    assert!(success.is_success());
+    assert!(failure.is_failure());
+    assert!(forward.is_forward());
 }
 
 #[test]
 fn wrap_in_option() {
-    assert_eq!(Success.or_none("test"), Some("test"));
-    assert_eq!(Failure.or_none("test"), None);
+    let success: Outcome<&str, &str> = Success("test");
+    let failure: Outcome<&str, &str> = Failure("bad");
+    let forward: Outcome<&str, &str> = Forward;
+
+    assert_eq!(success.success(), Some("test"));
+    assert_eq!(failure.success(), None);
+    assert_eq!(forward.success(), None);
+
+    assert_eq!(failure.failure(), Some("bad"));
 }
 
 #[test]
 fn map_to_closure() {
-    assert_eq!(Success.and_then(|| Success), Success);
-    assert_eq!(Failure.or_then(|| Success), Success);
+    let success: Outcome<i32, &str> = Success(1);
+    let failure: Outcome<i32, &str> = Failure("bad");
+    let forward: Outcome<i32, &str> = Forward;
+
+    assert_eq!(success.and_then(|n| Success(n + 1)), Success(2));
+
+    let recovered: Outcome<i32, &str> = failure.or_then(|_| Success(2));
+    assert_eq!(recovered, Success(2));
+
+    let recovered: Outcome<i32, &str> = forward.or_then(|_| Success(2));
+    assert_eq!(recovered, Forward);
+
+    let mapped: Outcome<i32, &str> = Success(1).map(|n: i32| n + 1);
+    assert_eq!(mapped, Success(2));
+
+    let mapped: Outcome<i32, usize> = Failure("bad").map_failure(|e: &str| e.len());
+    assert_eq!(mapped, Failure(3));
 }
 
 #[test]
 fn binary_ops() {
-    assert_eq!(Success.and(Success), Success);
-    assert_eq!(Success.and(Failure), Failure);
+    let success: Outcome<i32, &str> = Success(1);
+
+    assert_eq!(success.and(Success(2)), Success(2));
+
+    let chained: Outcome<i32, &str> = success.and(Failure("bad"));
+    assert_eq!(chained, Failure("bad"));
+
+    let chained: Outcome<i32, &str> = success.and(Forward);
+    assert_eq!(chained, Forward);
+
+    let failure: Outcome<i32, &str> = Failure("bad");
+    let recovered: Outcome<i32, &str> = failure.or(Success(1));
+    assert_eq!(recovered, Success(1));
+    let failure: Outcome<i32, &str> = Failure("bad");
+    assert_eq!(failure.or(Failure("worse")), Failure("worse"));
+    let forward: Outcome<i32, &str> = Forward;
+    let recovered: Outcome<i32, &str> = forward.or(Success(1));
+    assert_eq!(recovered, Success(1));
+}
+
+#[test]
+fn forward_ops() {
+    let forward: Outcome<i32, &str> = Forward;
+    let success: Outcome<i32, &str> = Success(1);
+    let failure: Outcome<i32, &str> = Failure("bad");
+
+    assert_eq!(forward.forward_then(|| Success(1)), Success(1));
+    assert_eq!(success.forward_then(|| Failure("bad")), Success(1));
+    assert_eq!(failure.forward_then(|| Success(1)), Failure("bad"));
+
+    let forward: Outcome<i32, &str> = Forward;
+    assert_eq!(forward.or_forward(Success(1)), Success(1));
+    let success: Outcome<i32, &str> = Success(1);
+    assert_eq!(success.or_forward(Forward), Success(1));
+    let failure: Outcome<i32, &str> = Failure("bad");
+    assert_eq!(failure.or_forward(Success(1)), Failure("bad"));
+}
+
+#[test]
+fn unwrapping() {
+    let success: Outcome<i32, &str> = Success(1);
+    assert_eq!(success.unwrap(), 1);
+    assert_eq!(Failure("bad").unwrap_or(0), 0);
+
+    let failure: Outcome<i32, &str> = Failure("bad");
+    assert_eq!(failure.unwrap_or_else(|e| e.map_or(0, str::len) as i32), 3);
+
+    let forward: Outcome<i32, &str> = Forward;
+    assert_eq!(forward.unwrap_or_else(|e| e.map_or(0, str::len) as i32), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_unwrap_on_failure() {
+    let failure: Outcome<i32, &str> = Failure("bad");
+    failure.unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_expect_on_failure() {
+    let failure: Outcome<i32, &str> = Failure("bad");
+    failure.expect("should have a value");
+}
+
+#[test]
+#[should_panic]
+fn test_expect_on_forward() {
+    let forward: Outcome<i32, &str> = Forward;
+    forward.expect("should have a value");
+}
+
+#[test]
+fn into_result() {
+    let success: Outcome<i32, &str> = Success(1);
+    let failure: Outcome<i32, &str> = Failure("bad");
+    let forward: Outcome<i32, &str> = Forward;
+
+    assert_eq!(success.into_result(), Ok(1));
+    assert_eq!(failure.into_result(), Err(Some("bad")));
+    assert_eq!(forward.into_result(), Err(None));
+}
+
+#[test]
+fn wrap_in_option_and_result_with_fixed_values() {
+    let success: Outcome<i32, &str> = Success(1);
+    let failure: Outcome<i32, &str> = Failure("bad");
+    let forward: Outcome<i32, &str> = Forward;
+
+    assert_eq!(success.or_none("test"), Some("test"));
+    assert_eq!(failure.or_none("test"), None);
+    assert_eq!(forward.or_none("test"), None);
+
+    let success: Outcome<i32, &str> = Success(1);
+    assert_eq!(success.or_err("good", "bad"), Ok("good"));
+    let failure: Outcome<i32, &str> = Failure("bad");
+    assert_eq!(failure.or_err("good", "bad"), Err("bad"));
+
+    let success: Outcome<i32, &str> = Success(1);
+    assert_eq!(success.or_panic(42), 42);
+}
 
-    assert_eq!(Success.or(Failure), Success);
-    assert_eq!(Failure.or(Failure), Failure);
+#[test]
+#[should_panic]
+fn test_or_panic_on_failure() {
+    let failure: Outcome<i32, &str> = Failure("bad");
+    failure.or_panic(42);
 }
 
 #[test]
 #[should_panic]
-fn test_or_panic() {
-    assert_eq!(Failure.or_panic(42), 42);
-}
\ No newline at end of file
+fn test_or_panic_on_forward() {
+    let forward: Outcome<i32, &str> = Forward;
+    forward.or_panic(42);
+}
+
+#[test]
+fn from_bool() {
+    assert_eq!(Outcome::from_bool(true), Success(()));
+    assert_eq!(Outcome::from_bool(false), Failure(()));
+}
+
+#[test]
+fn iter_yields_success_value() {
+    let success: Outcome<i32, &str> = Success(1);
+    let failure: Outcome<i32, &str> = Failure("bad");
+    let forward: Outcome<i32, &str> = Forward;
+
+    assert_eq!(success.iter().collect::<Vec<_>>(), vec![&1]);
+    assert_eq!(failure.iter().collect::<Vec<_>>(), Vec::<&i32>::new());
+    assert_eq!(forward.iter().collect::<Vec<_>>(), Vec::<&i32>::new());
+}
+
+#[test]
+fn iter_mut_allows_updating_success_value() {
+    let mut success: Outcome<i32, &str> = Success(1);
+    for n in success.iter_mut() {
+        *n += 1;
+    }
+    assert_eq!(success, Success(2));
+}
+
+#[test]
+fn into_iter_consumes_success_value() {
+    let success: Outcome<i32, &str> = Success(1);
+    assert_eq!(success.into_iter().collect::<Vec<_>>(), vec![1]);
+
+    let failure: Outcome<i32, &str> = Failure("bad");
+    assert_eq!(failure.into_iter().collect::<Vec<_>>(), Vec::<i32>::new());
+}
+
+#[test]
+fn flatten_extracts_all_successes() {
+    let outcomes: Vec<Outcome<i32, &str>> = vec![Success(1), Failure("bad"), Success(3)];
+    let successes: Vec<i32> = outcomes.iter().flatten().cloned().collect();
+    assert_eq!(successes, vec![1, 3]);
+}
+
+#[test]
+fn collect_unit_outcomes() {
+    let all: Outcome<(), &str> = vec![Success(()), Success(())].into_iter().collect();
+    assert_eq!(all, Success(()));
+
+    let some: Outcome<(), &str> = vec![Success(()), Failure("bad"), Success(())].into_iter().collect();
+    assert_eq!(some, Failure("bad"));
+
+    let forwarded: Outcome<(), &str> = vec![Success(()), Forward, Failure("bad")].into_iter().collect();
+    assert_eq!(forwarded, Forward);
+}
+
+#[test]
+fn collect_into_vec() {
+    let all: Outcome<Vec<i32>, &str> = vec![Success(1), Success(2), Success(3)].into_iter().collect();
+    assert_eq!(all, Success(vec![1, 2, 3]));
+
+    let some: Outcome<Vec<i32>, &str> = vec![Success(1), Failure("bad"), Success(3)].into_iter().collect();
+    assert_eq!(some, Failure("bad"));
+}
+
+#[test]
+fn all_and_any_success() {
+    let batch: Vec<Outcome<(), &str>> = vec![Success(()), Success(())];
+    assert_eq!(all_success(batch), Success(()));
+
+    let batch: Vec<Outcome<(), &str>> = vec![Success(()), Failure("bad")];
+    assert_eq!(all_success(batch), Failure("bad"));
+
+    let batch: Vec<Outcome<i32, &str>> = vec![Failure("bad"), Success(1)];
+    assert!(any_success(batch));
+
+    let batch: Vec<Outcome<i32, &str>> = vec![Failure("bad"), Forward];
+    assert!(!any_success(batch));
+}
+
+#[cfg(feature = "nightly")]
+mod try_operator {
+    use outcome::*;
+
+    fn parse(s: &str) -> Outcome<i32, String> {
+        let n: i32 = s.parse().map_err(|e: std::num::ParseIntError| e.to_string())?;
+        Success(n)
+    }
+
+    fn double(s: &str) -> Outcome<i32, String> {
+        let n = parse(s)?;
+        Success(n * 2)
+    }
+
+    #[test]
+    fn propagates_success_through_nested_calls() {
+        assert_eq!(double("21"), Success(42));
+    }
+
+    #[test]
+    fn propagates_failure_through_nested_calls() {
+        assert!(double("not a number").is_failure());
+    }
+
+    fn first_some(opt: Option<i32>) -> Outcome<i32, String> {
+        let n = opt?;
+        Success(n)
+    }
+
+    #[test]
+    fn propagates_none_as_failure() {
+        assert_eq!(first_some(Some(7)), Success(7));
+        assert_eq!(first_some(None), Failure(String::new()));
+    }
+}